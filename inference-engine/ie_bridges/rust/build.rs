@@ -1,5 +1,5 @@
 use std::path::{Path, PathBuf};
-use std::{fs, io};
+use std::{env, fs, io};
 
 fn main() {
     // Note which files will trigger a rebuild.
@@ -14,15 +14,18 @@ fn main() {
     // Generate C API bindings for good measure.
     generate_c_api("../c/include/c_api/ie_c_api.h");
 
+    // Locate the OpenVINO libraries, honoring the environment before falling back to
+    // pkg-config and the in-tree layout.
+    let openvino_lib_dir = find_openvino_lib_dir();
+
     // Link libraries.
-    let openvino_lib_dir = "../../../bin/intel64/Debug/lib";
-    link_libraries(openvino_lib_dir);
+    link_libraries(&openvino_lib_dir);
 
     // Copy in the plugins.xml file.
-    copy_openvino_plugin_file(openvino_lib_dir);
+    copy_openvino_plugin_file(&openvino_lib_dir);
 
     // Add the OpenVINO libraries to the runtime linking path.
-    add_library_run_path(openvino_lib_dir);
+    add_library_run_path(&openvino_lib_dir);
 }
 
 /// Helper to mark which files trigger a rerun of the build.
@@ -30,14 +33,98 @@ fn mark_rerun_files() {
     // Trigger rebuild on changes to build.rs and Cargo.toml...
     println!("cargo:rerun-if-changed=build.rs");
     println!("cargo:rerun-if-changed=Cargo.toml");
+    println!("cargo:rerun-if-env-changed=OPENVINO_INSTALL_DIR");
+    println!("cargo:rerun-if-env-changed=OPENVINO_BUILD_DIR");
+    println!("cargo:rerun-if-env-changed=INTEL_OPENVINO_DIR");
 
     // ...as well as every source file.
     let cb = |p: PathBuf| println!("cargo:rerun-if-changed={}", p.display());
     visit_dirs(Path::new("src"), &cb).expect("to visit source files");
 }
 
+/// Locate the directory containing OpenVINO's shared libraries and `plugins.xml`, trying (in
+/// order): an explicit `OPENVINO_BUILD_DIR` (the in-tree build output layout), an explicit
+/// `OPENVINO_INSTALL_DIR` or `INTEL_OPENVINO_DIR` (the standard installed/`setupvars.sh` layout),
+/// `pkg-config`, and finally the legacy hardcoded in-tree path for backwards compatibility. Panics
+/// listing every location searched if none of them pan out, so a downstream user building against
+/// a system-installed OpenVINO gets an actionable error instead of a missing-file failure deep in
+/// the linker.
+fn find_openvino_lib_dir() -> PathBuf {
+    let profile = build_profile_dir_name();
+    let mut searched = Vec::new();
+
+    if let Ok(dir) = env::var("OPENVINO_BUILD_DIR") {
+        // The in-tree build output layout: `<OPENVINO_BUILD_DIR>/bin/intel64/<Debug|Release>/lib`.
+        let candidate = PathBuf::from(dir).join("bin/intel64").join(&profile).join("lib");
+        if is_valid_lib_dir(&candidate) {
+            return candidate.canonicalize().unwrap();
+        }
+        searched.push(candidate);
+    }
+
+    for var in ["OPENVINO_INSTALL_DIR", "INTEL_OPENVINO_DIR"] {
+        if let Ok(dir) = env::var(var) {
+            let root = PathBuf::from(dir);
+            // Try the modern installed layout first, then the older `deployment_tools` one.
+            for candidate in [
+                root.join("runtime/lib/intel64"),
+                root.join("deployment_tools/inference_engine/lib/intel64").join(&profile),
+            ] {
+                if is_valid_lib_dir(&candidate) {
+                    return candidate.canonicalize().unwrap();
+                }
+                searched.push(candidate);
+            }
+        }
+    }
+
+    if let Some(dir) = pkg_config_lib_dir() {
+        if is_valid_lib_dir(&dir) {
+            return dir.canonicalize().unwrap();
+        }
+        searched.push(dir);
+    }
+
+    // Legacy fallback: building from inside OpenVINO's own source tree.
+    let in_tree = PathBuf::from("../../../bin/intel64").join(&profile).join("lib");
+    if is_valid_lib_dir(&in_tree) {
+        return in_tree.canonicalize().unwrap();
+    }
+    searched.push(in_tree);
+
+    panic!(
+        "Unable to locate an OpenVINO installation; searched:\n{}\n\
+         Set OPENVINO_INSTALL_DIR (or INTEL_OPENVINO_DIR) to an installed OpenVINO's root, \
+         or OPENVINO_BUILD_DIR to an in-tree build's root, and try again.",
+        searched
+            .iter()
+            .map(|p| format!("  - {}", p.display()))
+            .collect::<Vec<_>>()
+            .join("\n")
+    );
+}
+
+/// Map Cargo's `PROFILE` env var to the directory name OpenVINO's own build uses.
+fn build_profile_dir_name() -> &'static str {
+    match env::var("PROFILE").as_deref() {
+        Ok("release") => "Release",
+        _ => "Debug",
+    }
+}
+
+/// A directory "counts" as an OpenVINO lib dir if it exists and contains `plugins.xml`.
+fn is_valid_lib_dir(path: &Path) -> bool {
+    path.is_dir() && path.join("plugins.xml").is_file()
+}
+
+/// Ask `pkg-config` for OpenVINO's library directory, if it is installed and known to it.
+fn pkg_config_lib_dir() -> Option<PathBuf> {
+    let library = pkg_config::Config::new().probe("openvino").ok()?;
+    library.link_paths.into_iter().next()
+}
+
 /// Helper for linking the libraries necessary to build.
-fn link_libraries(openvino_lib_dir: &str) {
+fn link_libraries(openvino_lib_dir: &Path) {
     add_library_search_path(openvino_lib_dir);
 
     // Dynamically link in OpenVINO's inference engine (and dependencies).
@@ -88,15 +175,15 @@ fn visit_dirs(dir: &Path, cb: &dyn Fn(PathBuf)) -> io::Result<()> {
 }
 
 /// Copy the necessary OpenVINO plugin file to the correct location.
-fn copy_openvino_plugin_file(openvino_lib_dir: &str) {
+fn copy_openvino_plugin_file(openvino_lib_dir: &Path) {
     // Create a lib directory next to the built binary; this is the default location OpenVINO expects.
-    let profile = std::env::var("PROFILE").unwrap();
+    let profile = env::var("PROFILE").unwrap();
     let deps_lib_dir = format!("target/{}/deps/lib", profile);
     std::fs::create_dir_all(&deps_lib_dir).expect("to create the directory");
 
     // Copy the plugins.xml file.
     std::fs::copy(
-        format!("{}/plugins.xml", &openvino_lib_dir),
+        openvino_lib_dir.join("plugins.xml"),
         format!("{}/plugins.xml", &deps_lib_dir),
     )
     .expect("to copy the plugins.xml file");
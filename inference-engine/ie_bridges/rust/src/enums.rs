@@ -0,0 +1,160 @@
+//! Type-safe wrappers around the raw `c::precision_e`/`c::layout_e`/`c::resize_alg_e` constants
+//! so that callers do not have to reach into [binding::c] directly.
+
+use crate::binding::c;
+
+/// See [Precision](https://docs.openvinotoolkit.org/latest/classInferenceEngine_1_1Precision.html).
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Precision {
+    Unspecified,
+    Mixed,
+    FP32,
+    FP16,
+    Q78,
+    I16,
+    U8,
+    I8,
+    U16,
+    I32,
+    I64,
+    Bin,
+    Custom,
+}
+
+impl From<c::precision_e> for Precision {
+    fn from(p: c::precision_e) -> Self {
+        match p {
+            c::precision_e_MIXED => Precision::Mixed,
+            c::precision_e_FP32 => Precision::FP32,
+            c::precision_e_FP16 => Precision::FP16,
+            c::precision_e_Q78 => Precision::Q78,
+            c::precision_e_I16 => Precision::I16,
+            c::precision_e_U8 => Precision::U8,
+            c::precision_e_I8 => Precision::I8,
+            c::precision_e_U16 => Precision::U16,
+            c::precision_e_I32 => Precision::I32,
+            c::precision_e_I64 => Precision::I64,
+            c::precision_e_BIN => Precision::Bin,
+            c::precision_e_CUSTOM => Precision::Custom,
+            _ => Precision::Unspecified,
+        }
+    }
+}
+
+impl From<Precision> for c::precision_e {
+    fn from(p: Precision) -> Self {
+        match p {
+            Precision::Unspecified => c::precision_e_UNSPECIFIED,
+            Precision::Mixed => c::precision_e_MIXED,
+            Precision::FP32 => c::precision_e_FP32,
+            Precision::FP16 => c::precision_e_FP16,
+            Precision::Q78 => c::precision_e_Q78,
+            Precision::I16 => c::precision_e_I16,
+            Precision::U8 => c::precision_e_U8,
+            Precision::I8 => c::precision_e_I8,
+            Precision::U16 => c::precision_e_U16,
+            Precision::I32 => c::precision_e_I32,
+            Precision::I64 => c::precision_e_I64,
+            Precision::Bin => c::precision_e_BIN,
+            Precision::Custom => c::precision_e_CUSTOM,
+        }
+    }
+}
+
+/// See [Layout](https://docs.openvinotoolkit.org/latest/classInferenceEngine_1_1Layout.html).
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Layout {
+    Any,
+    NCHW,
+    NHWC,
+    NCDHW,
+    NDHWC,
+    OIHW,
+    GOIHW,
+    OIDHW,
+    GOIDHW,
+    Scalar,
+    C,
+    CHW,
+    HW,
+    NC,
+    CN,
+    Blocked,
+}
+
+impl From<c::layout_e> for Layout {
+    fn from(l: c::layout_e) -> Self {
+        match l {
+            c::layout_e_NCHW => Layout::NCHW,
+            c::layout_e_NHWC => Layout::NHWC,
+            c::layout_e_NCDHW => Layout::NCDHW,
+            c::layout_e_NDHWC => Layout::NDHWC,
+            c::layout_e_OIHW => Layout::OIHW,
+            c::layout_e_GOIHW => Layout::GOIHW,
+            c::layout_e_OIDHW => Layout::OIDHW,
+            c::layout_e_GOIDHW => Layout::GOIDHW,
+            c::layout_e_SCALAR => Layout::Scalar,
+            c::layout_e_C => Layout::C,
+            c::layout_e_CHW => Layout::CHW,
+            c::layout_e_HW => Layout::HW,
+            c::layout_e_NC => Layout::NC,
+            c::layout_e_CN => Layout::CN,
+            c::layout_e_BLOCKED => Layout::Blocked,
+            _ => Layout::Any,
+        }
+    }
+}
+
+impl From<Layout> for c::layout_e {
+    fn from(l: Layout) -> Self {
+        match l {
+            Layout::Any => c::layout_e_ANY,
+            Layout::NCHW => c::layout_e_NCHW,
+            Layout::NHWC => c::layout_e_NHWC,
+            Layout::NCDHW => c::layout_e_NCDHW,
+            Layout::NDHWC => c::layout_e_NDHWC,
+            Layout::OIHW => c::layout_e_OIHW,
+            Layout::GOIHW => c::layout_e_GOIHW,
+            Layout::OIDHW => c::layout_e_OIDHW,
+            Layout::GOIDHW => c::layout_e_GOIDHW,
+            Layout::Scalar => c::layout_e_SCALAR,
+            Layout::C => c::layout_e_C,
+            Layout::CHW => c::layout_e_CHW,
+            Layout::HW => c::layout_e_HW,
+            Layout::NC => c::layout_e_NC,
+            Layout::CN => c::layout_e_CN,
+            Layout::Blocked => c::layout_e_BLOCKED,
+        }
+    }
+}
+
+/// See [ResizeAlgorithm](https://docs.openvinotoolkit.org/latest/ie_c_api/ie__c__api_8h.html).
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ResizeAlgorithm {
+    NoResize,
+    Bilinear,
+    Area,
+}
+
+impl From<c::resize_alg_e> for ResizeAlgorithm {
+    fn from(r: c::resize_alg_e) -> Self {
+        match r {
+            c::resize_alg_e_RESIZE_BILINEAR => ResizeAlgorithm::Bilinear,
+            c::resize_alg_e_RESIZE_AREA => ResizeAlgorithm::Area,
+            _ => ResizeAlgorithm::NoResize,
+        }
+    }
+}
+
+impl From<ResizeAlgorithm> for c::resize_alg_e {
+    fn from(r: ResizeAlgorithm) -> Self {
+        match r {
+            ResizeAlgorithm::NoResize => c::resize_alg_e_NO_RESIZE,
+            ResizeAlgorithm::Bilinear => c::resize_alg_e_RESIZE_BILINEAR,
+            ResizeAlgorithm::Area => c::resize_alg_e_RESIZE_AREA,
+        }
+    }
+}
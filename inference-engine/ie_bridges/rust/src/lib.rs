@@ -6,15 +6,22 @@
 //! wrap into a [UniquePtr]; therefore, we manually wrap these in `binding/cpp/bridge.h`
 //! before using in Rust. See https://github.com/dtolnay/cxx/issues/228 for discussion.
 //!
-//! TODO The Drop implementations for these structures still must be implemented.
+//! Structures wrapping `cxx`'s [UniquePtr] (e.g. [Core], [CNNNetwork], [ExecutableNetwork],
+//! [InferRequest]) are freed automatically when the `UniquePtr` drops. [Blob] wraps a raw
+//! C-API pointer instead and implements `Drop` itself, freeing only the memory it owns (see
+//! the `owned` field).
 
 mod binding;
+mod enums;
 
 use binding::c;
 use binding::cpp;
+use bytemuck::Pod;
 use cxx::UniquePtr;
 use std::convert::TryFrom;
 
+pub use enums::{Layout, Precision, ResizeAlgorithm};
+
 /// See [Core](https://docs.openvinotoolkit.org/latest/classInferenceEngine_1_1Core.html).
 pub struct Core {
     instance: UniquePtr<cpp::Core>,
@@ -38,6 +45,81 @@ impl Core {
         let instance = cpp::load_network(&mut self.instance, network.instance, device);
         ExecutableNetwork { instance }
     }
+
+    /// Load `network` onto `device` after applying `config` (each `(key, value)` pair is passed
+    /// to [Core::set_config] first), for tuning e.g. throughput/stream settings per device.
+    pub fn load_network_with_config(
+        &mut self,
+        network: CNNNetwork,
+        device: &str,
+        config: &[(&str, &str)],
+    ) -> Result<ExecutableNetwork, InferenceError> {
+        self.set_config(device, config)?;
+        Ok(self.load_network(network, device))
+    }
+
+    /// List the devices (e.g. `"CPU"`, `"GPU"`, `"MYRIAD"`, `"HETERO:FPGA,CPU"`) that this
+    /// [Core] can currently see.
+    pub fn available_devices(&mut self) -> Result<Vec<String>, InferenceError> {
+        let mut devices: c::ie_available_devices_t = unsafe { std::mem::zeroed() };
+        let devices_ptr = &mut devices as *mut c::ie_available_devices_t;
+        let result = unsafe { c::ie_core_get_available_devices(self.as_mut(), devices_ptr) };
+        InferenceError::from(result)?;
+
+        let names = unsafe { std::slice::from_raw_parts(devices.devices, devices.num_devices as usize) }
+            .iter()
+            .map(|&name| {
+                unsafe { std::ffi::CStr::from_ptr(name) }
+                    .to_string_lossy()
+                    .into_owned()
+            })
+            .collect();
+        unsafe { c::ie_core_available_devices_free(devices_ptr) };
+        Ok(names)
+    }
+
+    /// Set one or more `(key, value)` configuration options (e.g. stream counts, performance
+    /// hints) on `device`.
+    pub fn set_config(&mut self, device: &str, config: &[(&str, &str)]) -> Result<(), InferenceError> {
+        let device_name = std::ffi::CString::new(device).unwrap();
+        for (key, value) in config {
+            let key = std::ffi::CString::new(*key).unwrap();
+            let value = std::ffi::CString::new(*value).unwrap();
+            let ie_config = c::ie_config_t {
+                name: key.as_ptr() as *mut std::os::raw::c_char,
+                value: value.as_ptr() as *mut std::os::raw::c_char,
+                next: std::ptr::null_mut(),
+            };
+            let result =
+                unsafe { c::ie_core_set_config(self.as_mut(), &ie_config, device_name.as_ptr()) };
+            InferenceError::from(result)?;
+        }
+        Ok(())
+    }
+
+    /// Read a metric (e.g. `"AVAILABLE_DEVICES"`, `"OPTIMIZATION_CAPABILITIES"`) from `device`.
+    pub fn get_metric(&mut self, device: &str, metric_name: &str) -> Result<String, InferenceError> {
+        let device_name = std::ffi::CString::new(device).unwrap();
+        let metric_name = std::ffi::CString::new(metric_name).unwrap();
+        let mut param: c::ie_param_t = unsafe { std::mem::zeroed() };
+        let result = unsafe {
+            c::ie_core_get_metric(
+                self.as_mut(),
+                device_name.as_ptr(),
+                metric_name.as_ptr(),
+                &mut param,
+            )
+        };
+        InferenceError::from(result)?;
+        Ok(unsafe { std::ffi::CStr::from_ptr(param.__bindgen_anon_1.params) }
+            .to_string_lossy()
+            .into_owned())
+    }
+
+    fn as_mut(&mut self) -> *mut c::ie_core_t {
+        // FIXME likely will cause bugs; relies on the pointer to the core being at offset 0 in both UniquePtr and ie_core_t.
+        &mut *self.instance as *mut cpp::Core as *mut c::ie_core_t
+    }
 }
 
 /// See [CNNNetwork](https://docs.openvinotoolkit.org/latest/classInferenceEngine_1_1CNNNetwork.html).
@@ -87,19 +169,29 @@ impl CNNNetwork {
         output_name: &str,
     ) -> Result<(), InferenceError> {
         let network = self.as_mut();
-        let input_name = std::ffi::CString::new(input_name).unwrap().into_raw();
-        let output_name = std::ffi::CString::new(output_name).unwrap().into_raw();
+        // Keep the `CString`s alive for the duration of the calls below instead of leaking them
+        // via `into_raw`; the C API only reads the names, it does not take ownership of them.
+        let input_name = std::ffi::CString::new(input_name).unwrap();
+        let output_name = std::ffi::CString::new(output_name).unwrap();
+        let input_name_ptr = input_name.as_ptr() as *mut std::os::raw::c_char;
+        let output_name_ptr = output_name.as_ptr() as *mut std::os::raw::c_char;
         let mut status = c::IEStatusCode_OK;
         unsafe {
             status |= c::ie_network_set_input_resize_algorithm(
                 network,
-                input_name,
-                c::resize_alg_e_RESIZE_BILINEAR,
+                input_name_ptr,
+                ResizeAlgorithm::Bilinear.into(),
             );
-            status |= c::ie_network_set_input_layout(network, input_name, c::layout_e_NHWC);
-            status |= c::ie_network_set_input_precision(network, input_name, c::precision_e_U8);
+            status |=
+                c::ie_network_set_input_layout(network, input_name_ptr, Layout::NHWC.into());
+            status |=
+                c::ie_network_set_input_precision(network, input_name_ptr, Precision::U8.into());
 
-            status |= c::ie_network_set_output_precision(network, output_name, c::precision_e_FP32);
+            status |= c::ie_network_set_output_precision(
+                network,
+                output_name_ptr,
+                Precision::FP32.into(),
+            );
         }
         InferenceError::from(status)
     }
@@ -113,29 +205,46 @@ pub struct ExecutableNetwork {
 impl ExecutableNetwork {
     pub fn create_infer_request(&mut self) -> InferRequest {
         let instance = cpp::create_infer_request(&mut self.instance);
-        InferRequest { instance }
+        InferRequest {
+            instance,
+            callback: None,
+        }
     }
 }
 
 /// See [InferRequest](https://docs.openvinotoolkit.org/latest/classInferenceEngine_1_1InferRequest.html).
 pub struct InferRequest {
     instance: UniquePtr<cpp::InferRequest>,
+    /// The completion callback registered via [InferRequest::set_completion_callback], if any;
+    /// kept alive here so the pointer handed to the C API via `user_data` stays valid for as
+    /// long as OpenVINO may invoke it, and is freed when this [InferRequest] (or a replacement
+    /// callback) drops.
+    callback: Option<Box<Box<dyn FnMut() + Send + 'static>>>,
 }
 
 impl InferRequest {
     pub fn set_blob(&mut self, name: &str, blob: Blob) -> Result<(), InferenceError> {
         let infer_request_ptr = self.as_mut();
-        let name_ptr = std::ffi::CString::new(name).unwrap().into_raw();
+        let name = std::ffi::CString::new(name).unwrap();
+        let name_ptr = name.as_ptr() as *mut std::os::raw::c_char;
+        // `ie_infer_request_set_blob` keeps its own reference to the underlying blob rather than
+        // taking ownership of our handle, so `blob` still needs to free its own handle when
+        // dropped at the end of this function; see `Blob::drop`.
         let blob_ptr = blob.internal;
         let result = unsafe { c::ie_infer_request_set_blob(infer_request_ptr, name_ptr, blob_ptr) };
         InferenceError::from(result)
     }
 
     pub fn get_blob(&mut self, name: &str) -> Result<Blob, InferenceError> {
-        let name_ptr = std::ffi::CString::new(name).unwrap().into_raw();
+        let name = std::ffi::CString::new(name).unwrap();
+        let name_ptr = name.as_ptr() as *mut std::os::raw::c_char;
         let mut blob: *mut c::ie_blob_t = std::ptr::null_mut();
         let blob_ptr: *mut *mut c::ie_blob_t = &mut blob;
         let result = unsafe { c::ie_infer_request_get_blob(self.as_mut(), name_ptr, blob_ptr) };
+        // Like `ie_blob_make_memory`, `ie_infer_request_get_blob` allocates a fresh `ie_blob_t`
+        // handle into the out-param on every call (it wraps the request's underlying blob, but
+        // the handle itself is ours); we own it and must free it, so use `Blob::from` rather
+        // than `Blob::borrowed`.
         InferenceError::from(result).and(Ok(Blob::from(blob)))
     }
 
@@ -144,15 +253,91 @@ impl InferRequest {
         InferenceError::from(result)
     }
 
+    /// Start inference without blocking the calling thread; pair this with [InferRequest::wait]
+    /// or [InferRequest::set_completion_callback] to learn when it finishes. This allows
+    /// preprocessing to overlap with inference, or multiple requests to run concurrently across
+    /// device streams.
+    pub fn infer_async(&mut self) -> Result<(), InferenceError> {
+        let result = unsafe { c::ie_infer_request_infer_async(self.as_mut()) };
+        InferenceError::from(result)
+    }
+
+    /// Wait for an asynchronous inference started by [InferRequest::infer_async] to complete, up
+    /// to `timeout_ms` milliseconds (a negative value waits indefinitely). Returns
+    /// `Err(InferenceError { kind: InferenceErrorKind::ResultNotReady, .. })` if the timeout
+    /// elapses first.
+    pub fn wait(&mut self, timeout_ms: i64) -> Result<(), InferenceError> {
+        let result = unsafe { c::ie_infer_request_wait(self.as_mut(), timeout_ms) };
+        InferenceError::from(result)
+    }
+
+    /// Register a callback to be invoked by OpenVINO on its own thread once an asynchronous
+    /// inference started by [InferRequest::infer_async] completes. Registering a new callback
+    /// replaces and drops any previously registered one; to make sure OpenVINO is not still
+    /// about to invoke the old registration against freed memory, this first blocks on
+    /// [InferRequest::wait] so any in-flight `infer_async()` fires (or has already fired) the
+    /// previous callback before we drop it. Dropping the [InferRequest] itself is safe for the
+    /// same reason: see `Drop for InferRequest`.
+    pub fn set_completion_callback<F>(&mut self, callback: F) -> Result<(), InferenceError>
+    where
+        F: FnMut() + Send + 'static,
+    {
+        // Let any in-flight async inference complete (and thus invoke the *old* callback) before
+        // we free it below; otherwise OpenVINO could still be holding a `user_data` pointer into
+        // memory we are about to drop.
+        let _ = self.wait(-1);
+
+        // Box the closure twice: the inner `Box<dyn FnMut() + Send>` is a fat pointer (vtable +
+        // data), which does not fit in the C API's single `void*`; the outer `Box` gives us a
+        // thin pointer to hand over as `user_data` while keeping the allocation alive in `self`.
+        let mut boxed: Box<Box<dyn FnMut() + Send + 'static>> = Box::new(Box::new(callback));
+        let user_data =
+            boxed.as_mut() as *mut Box<dyn FnMut() + Send + 'static> as *mut std::os::raw::c_void;
+        let callback_struct = c::ie_complete_call_back_t {
+            completeCallBackFunc: Some(Self::trampoline),
+            args: user_data,
+        };
+        let result =
+            unsafe { c::ie_infer_request_set_completion_callback(self.as_mut(), &callback_struct) };
+        InferenceError::from(result)?;
+        self.callback = Some(boxed);
+        Ok(())
+    }
+
+    /// Bridges OpenVINO's C completion callback back into the boxed Rust closure stored in
+    /// `user_data`. OpenVINO invokes this on its own thread, which is why
+    /// [InferRequest::set_completion_callback] requires `Send`.
+    extern "C" fn trampoline(user_data: *mut std::os::raw::c_void) {
+        let closure = unsafe { &mut *(user_data as *mut Box<dyn FnMut() + Send + 'static>) };
+        closure();
+    }
+
     fn as_mut(&mut self) -> *mut c::ie_infer_request_t {
         // FIXME likely will cause bugs; relies on the pointer to the request being at offset 0 in both UniquePtr and ie_network_t.
         &mut *self.instance as *mut cpp::InferRequest as *mut c::ie_infer_request_t
     }
 }
 
+impl Drop for InferRequest {
+    /// Block until any in-flight [InferRequest::infer_async] completes before releasing
+    /// `callback`. Without this, OpenVINO's executor (which keeps its own reference to the
+    /// underlying C++ request independent of our `UniquePtr`) could invoke the completion
+    /// callback's `trampoline` against memory we just freed.
+    fn drop(&mut self) {
+        if self.callback.is_some() {
+            let _ = self.wait(-1);
+        }
+    }
+}
+
 /// See [Blob](https://docs.openvinotoolkit.org/latest/classInferenceEngine_1_1Blob.html).
 pub struct Blob {
     internal: *mut c::ie_blob_t,
+    /// Whether this handle owns `internal` and must free it on drop. Handles we allocate
+    /// ourselves or receive fresh from the C API (`new`/`allocate`/`from`, including those
+    /// returned by [InferRequest::get_blob]) are owned; handles built with [Blob::borrowed] are
+    /// not.
+    owned: bool,
 }
 
 impl Blob {
@@ -175,17 +360,34 @@ impl Blob {
         Ok(blob)
     }
 
-    /// Allocate space in OpenVINO for an empty [Blob].
+    /// Allocate space in OpenVINO for an empty [Blob]; this [Blob] owns the allocation and will
+    /// free it on drop.
     pub fn allocate(description: TensorDesc) -> Result<Self, InferenceError> {
         let mut blob: *mut c::ie_blob_t = std::ptr::null_mut();
         let blob_ptr: *mut *mut c::ie_blob_t = &mut blob;
         let result = unsafe { c::ie_blob_make_memory(description.as_ptr(), blob_ptr) };
-        InferenceError::from(result).and(Ok(Self { internal: blob }))
+        InferenceError::from(result).and(Ok(Self {
+            internal: blob,
+            owned: true,
+        }))
     }
 
-    /// Construct a Blob from its associated pointer FIXME figure out drop behavior.
+    /// Construct a [Blob] from a pointer we own, e.g. one just allocated via the C API; it will
+    /// be freed on drop.
     pub fn from(pointer: *mut c::ie_blob_t) -> Self {
-        Self { internal: pointer }
+        Self {
+            internal: pointer,
+            owned: true,
+        }
+    }
+
+    /// Construct a [Blob] from a pointer we are borrowing from OpenVINO; it will *not* be freed
+    /// on drop since OpenVINO still owns it.
+    pub fn borrowed(pointer: *mut c::ie_blob_t) -> Self {
+        Self {
+            internal: pointer,
+            owned: false,
+        }
     }
 
     /// Return the tensor description of this [Blob].
@@ -206,15 +408,25 @@ impl Blob {
         InferenceError::from(result)?;
 
         let mut precision: c::precision_e = 0;
-        let precision_ptr = &mut precision as *mut c::layout_e;
+        let precision_ptr = &mut precision as *mut c::precision_e;
         let result = unsafe { c::ie_blob_get_precision(blob, precision_ptr) };
         InferenceError::from(result)?;
 
-        Ok(TensorDesc::new(precision, &dimensions.dims, precision))
+        Ok(TensorDesc::new(
+            Layout::from(layout),
+            &dimensions.dims[..dimensions.ranks as usize],
+            Precision::from(precision),
+        ))
+    }
+
+    /// Create a new [Blob] by copying a strongly-typed slice in to OpenVINO-allocated memory,
+    /// checking that `slice`'s byte length matches `description`'s.
+    pub fn from_slice<T: Pod>(description: TensorDesc, slice: &[T]) -> Result<Self, InferenceError> {
+        Self::new(description, bytemuck::cast_slice(slice))
     }
 
     /// Get the number of elements contained in the Blob.
-    pub fn len(&mut self) -> Result<usize, InferenceError> {
+    pub fn len(&self) -> Result<usize, InferenceError> {
         let mut size = 0;
         let size_ptr = &mut size as *mut std::os::raw::c_int;
         let result = unsafe { c::ie_blob_size(self.internal, size_ptr) };
@@ -222,24 +434,47 @@ impl Blob {
     }
 
     /// Get the size of the current Blob in bytes.
-    pub fn byte_len(&mut self) -> Result<usize, InferenceError> {
+    pub fn byte_len(&self) -> Result<usize, InferenceError> {
         let mut size = 0;
         let size_ptr = &mut size as *mut std::os::raw::c_int;
         let result = unsafe { c::ie_blob_byte_size(self.internal, size_ptr) };
         InferenceError::from(result).and(Ok(usize::try_from(size).unwrap()))
     }
 
-    /// Retrieve the [Blob]'s data as a mutable slice.
-    pub fn buffer<T>(&mut self) -> Result<&mut [T], InferenceError> {
+    /// Retrieve the [Blob]'s data as a mutable slice. `T: Pod` rules out types with invalid-bit-
+    /// pattern or `Drop` semantics being reinterpreted over raw, possibly-uninitialized
+    /// OpenVINO-owned memory.
+    pub fn buffer<T: Pod>(&mut self) -> Result<&mut [T], InferenceError> {
+        let (buffer, len) = self.raw_buffer::<T>()?;
+        let slice = unsafe { std::slice::from_raw_parts_mut(buffer as *mut T, len) };
+        Ok(slice)
+    }
+
+    /// Retrieve the [Blob]'s data as a shared slice, analogous to `AsRef<[T]>`.
+    pub fn as_slice<T: Pod>(&self) -> Result<&[T], InferenceError> {
+        let (buffer, len) = self.raw_buffer::<T>()?;
+        let slice = unsafe { std::slice::from_raw_parts(buffer as *const T, len) };
+        Ok(slice)
+    }
+
+    /// Return the buffer pointer along with its length in units of `T`, derived from the
+    /// blob's byte size rather than [Blob::len] (which counts elements of the blob's *own*
+    /// precision, not necessarily `size_of::<T>()`).
+    fn raw_buffer<T>(&self) -> Result<(*mut std::os::raw::c_void, usize), InferenceError> {
         let mut buffer = Blob::empty_buffer();
         let buffer_ptr = &mut buffer as *mut c::ie_blob_buffer_t;
         let result = unsafe { c::ie_blob_get_buffer(self.internal, buffer_ptr) };
         InferenceError::from(result)?;
-        let size = self.len()?;
-        let slice = unsafe {
-            std::slice::from_raw_parts_mut(buffer.__bindgen_anon_1.buffer as *mut T, size)
-        };
-        Ok(slice)
+        let byte_len = self.byte_len()?;
+        let elem_size = std::mem::size_of::<T>();
+        assert_eq!(
+            byte_len % elem_size,
+            0,
+            "The blob's byte size ({} bytes) is not a multiple of the requested element size ({} bytes).",
+            byte_len,
+            elem_size
+        );
+        Ok((unsafe { buffer.__bindgen_anon_1.buffer }, byte_len / elem_size))
     }
 
     fn empty_buffer() -> c::ie_blob_buffer_t {
@@ -251,14 +486,32 @@ impl Blob {
     }
 }
 
+impl<T: Pod> TryFrom<&Blob> for Vec<T> {
+    type Error = InferenceError;
+
+    /// Copy a [Blob]'s data out into an owned, strongly-typed `Vec`.
+    fn try_from(blob: &Blob) -> Result<Self, InferenceError> {
+        Ok(blob.as_slice::<T>()?.to_vec())
+    }
+}
+
+impl Drop for Blob {
+    fn drop(&mut self) {
+        if self.owned && !self.internal.is_null() {
+            let blob_ptr: *mut *mut c::ie_blob_t = &mut self.internal;
+            unsafe { c::ie_blob_free(blob_ptr) };
+        }
+    }
+}
+
 /// See [TensorDesc](https://docs.openvinotoolkit.org/latest/classInferenceEngine_1_1TensorDesc.html).
 pub struct TensorDesc {
     internal: c::tensor_desc_t,
 }
 
 impl TensorDesc {
-    /// Construct a new [TensorDesc] from its C API components.
-    pub fn new(layout: c::layout_e, dimensions: &[u64], precision: c::precision_e) -> Self {
+    /// Construct a new [TensorDesc] from its layout, dimensions, and precision.
+    pub fn new(layout: Layout, dimensions: &[u64], precision: Precision) -> Self {
         // Setup dimensions.
         assert!(dimensions.len() < 8);
         let mut dims = [0; 8];
@@ -267,12 +520,12 @@ impl TensorDesc {
         // Create the description structure.
         Self {
             internal: c::tensor_desc_t {
-                layout,
+                layout: layout.into(),
                 dims: c::dimensions_t {
                     ranks: dimensions.len() as u64,
                     dims,
                 },
-                precision,
+                precision: precision.into(),
             },
         }
     }
@@ -292,8 +545,8 @@ impl TensorDesc {
 /// See [IEStatusCode](https://docs.openvinotoolkit.org/latest/ie_c_api/ie__c__api_8h.html#a391683b1e8e26df8b58d7033edd9ee83).
 /// TODO Replace this in bindgen with [newtype_enum](https://docs.rs/bindgen/0.54.1/bindgen/struct.Builder.html#method.newtype_enum)
 /// or [rustified_enum](https://docs.rs/bindgen/0.54.1/bindgen/struct.Builder.html#method.rustified_enum).
-#[derive(Debug)]
-pub enum InferenceError {
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum InferenceErrorKind {
     GeneralError,
     NotImplemented,
     NetworkNotLoaded,
@@ -309,34 +562,151 @@ pub enum InferenceError {
     Undefined,
 }
 
+/// An error returned by the OpenVINO C API: the [InferenceErrorKind] of the status code plus an
+/// optional human-readable `detail`, which `Display` prints after the kind and a colon (see the
+/// `inference_error_displays_its_kind_with_a_detail` test).
+///
+/// [InferenceError::from] maps the status code only and always sets `detail` to `None`; it
+/// reads no error message from OpenVINO.
+#[derive(Debug)]
+pub struct InferenceError {
+    pub kind: InferenceErrorKind,
+    pub detail: Option<String>,
+}
+
 impl InferenceError {
     pub fn from(e: i32) -> Result<(), InferenceError> {
-        use InferenceError::*;
-        match e {
-            c::IEStatusCode_OK => Ok(()),
-            c::IEStatusCode_GENERAL_ERROR => Err(GeneralError),
-            c::IEStatusCode_NOT_IMPLEMENTED => Err(NotImplemented),
-            c::IEStatusCode_NETWORK_NOT_LOADED => Err(NetworkNotLoaded),
-            c::IEStatusCode_PARAMETER_MISMATCH => Err(ParameterMismatch),
-            c::IEStatusCode_NOT_FOUND => Err(NotFound),
-            c::IEStatusCode_OUT_OF_BOUNDS => Err(OutOfBounds),
-            c::IEStatusCode_UNEXPECTED => Err(Unexpected),
-            c::IEStatusCode_REQUEST_BUSY => Err(RequestBusy),
-            c::IEStatusCode_RESULT_NOT_READY => Err(ResultNotReady),
-            c::IEStatusCode_NOT_ALLOCATED => Err(NotAllocated),
-            c::IEStatusCode_INFER_NOT_STARTED => Err(InferNotStarted),
-            c::IEStatusCode_NETWORK_NOT_READ => Err(NetworkNotReady),
-            _ => Err(Undefined),
+        use InferenceErrorKind::*;
+        let kind = match e {
+            c::IEStatusCode_OK => return Ok(()),
+            c::IEStatusCode_GENERAL_ERROR => GeneralError,
+            c::IEStatusCode_NOT_IMPLEMENTED => NotImplemented,
+            c::IEStatusCode_NETWORK_NOT_LOADED => NetworkNotLoaded,
+            c::IEStatusCode_PARAMETER_MISMATCH => ParameterMismatch,
+            c::IEStatusCode_NOT_FOUND => NotFound,
+            c::IEStatusCode_OUT_OF_BOUNDS => OutOfBounds,
+            c::IEStatusCode_UNEXPECTED => Unexpected,
+            c::IEStatusCode_REQUEST_BUSY => RequestBusy,
+            c::IEStatusCode_RESULT_NOT_READY => ResultNotReady,
+            c::IEStatusCode_NOT_ALLOCATED => NotAllocated,
+            c::IEStatusCode_INFER_NOT_STARTED => InferNotStarted,
+            c::IEStatusCode_NETWORK_NOT_READ => NetworkNotReady,
+            _ => Undefined,
+        };
+        Err(InferenceError { kind, detail: None })
+    }
+}
+
+impl std::fmt::Display for InferenceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.detail {
+            Some(detail) => write!(f, "{:?}: {}", self.kind, detail),
+            None => write!(f, "{:?}", self.kind),
         }
     }
 }
 
+impl std::error::Error for InferenceError {}
+
 #[cfg(test)]
 mod test {
     use super::*;
     use opencv::core::{MatTrait, MatTraitManual};
     use std::path::PathBuf;
 
+    #[test]
+    fn precision_round_trips_through_the_c_api() {
+        let all = [
+            Precision::Unspecified,
+            Precision::Mixed,
+            Precision::FP32,
+            Precision::FP16,
+            Precision::Q78,
+            Precision::I16,
+            Precision::U8,
+            Precision::I8,
+            Precision::U16,
+            Precision::I32,
+            Precision::I64,
+            Precision::Bin,
+            Precision::Custom,
+        ];
+        for precision in all {
+            assert_eq!(Precision::from(c::precision_e::from(precision)), precision);
+        }
+    }
+
+    #[test]
+    fn layout_round_trips_through_the_c_api() {
+        let all = [
+            Layout::Any,
+            Layout::NCHW,
+            Layout::NHWC,
+            Layout::NCDHW,
+            Layout::NDHWC,
+            Layout::OIHW,
+            Layout::GOIHW,
+            Layout::OIDHW,
+            Layout::GOIDHW,
+            Layout::Scalar,
+            Layout::C,
+            Layout::CHW,
+            Layout::HW,
+            Layout::NC,
+            Layout::CN,
+            Layout::Blocked,
+        ];
+        for layout in all {
+            assert_eq!(Layout::from(c::layout_e::from(layout)), layout);
+        }
+    }
+
+    #[test]
+    fn resize_algorithm_round_trips_through_the_c_api() {
+        let all = [
+            ResizeAlgorithm::NoResize,
+            ResizeAlgorithm::Bilinear,
+            ResizeAlgorithm::Area,
+        ];
+        for algorithm in all {
+            assert_eq!(
+                ResizeAlgorithm::from(c::resize_alg_e::from(algorithm)),
+                algorithm
+            );
+        }
+    }
+
+    #[test]
+    fn inference_error_displays_its_kind_without_a_detail() {
+        let error = InferenceError {
+            kind: InferenceErrorKind::ParameterMismatch,
+            detail: None,
+        };
+        assert_eq!(error.to_string(), "ParameterMismatch");
+    }
+
+    #[test]
+    fn inference_error_displays_its_kind_with_a_detail() {
+        let error = InferenceError {
+            kind: InferenceErrorKind::ParameterMismatch,
+            detail: Some("input 'image_tensor' expects FP32 but got U8".to_string()),
+        };
+        assert_eq!(
+            error.to_string(),
+            "ParameterMismatch: input 'image_tensor' expects FP32 but got U8"
+        );
+    }
+
+    #[test]
+    fn inference_error_is_a_std_error() {
+        fn assert_is_error<E: std::error::Error>(_: &E) {}
+        let error = InferenceError {
+            kind: InferenceErrorKind::Undefined,
+            detail: None,
+        };
+        assert_is_error(&error);
+    }
+
     // FIXME these tests rely on a pre-built model and images in the filesystem--avoid this.
     struct Fixture;
 
@@ -420,14 +790,14 @@ mod test {
         )
         .unwrap();
         let desc = TensorDesc::new(
-            c::layout_e_NHWC,
+            Layout::NHWC,
             &[
                 1,
                 mat.channels().unwrap() as u64,
                 mat.size().unwrap().height as u64,
                 mat.size().unwrap().width as u64, // TODO .try_into().unwrap()
             ], // {1, (size_t)img.mat_channels, (size_t)img.mat_height, (size_t)img.mat_width}
-            c::precision_e_U8,
+            Precision::U8,
         );
 
         // Extract the OpenCV mat bytes and place them in an OpenVINO blob.
@@ -437,8 +807,8 @@ mod test {
 
         infer_request.set_blob(&input_name, blob).unwrap();
         infer_request.infer().unwrap();
-        let mut results = infer_request.get_blob(&output_name).unwrap();
-        let buffer = results.buffer::<f32>().unwrap().to_vec();
+        let results = infer_request.get_blob(&output_name).unwrap();
+        let buffer = Vec::<f32>::try_from(&results).unwrap();
 
         // Sort results.
         #[derive(Debug, PartialEq)]